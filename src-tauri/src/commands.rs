@@ -0,0 +1,86 @@
+use std::thread;
+use std::time::Duration;
+
+use arboard::Clipboard;
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+
+#[cfg(target_os = "windows")]
+use std::sync::Mutex;
+
+#[cfg(target_os = "windows")]
+use windows::Win32::Foundation::HWND;
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, SetForegroundWindow};
+
+// Foreground window captured right before the overlay steals focus, so we
+// know where to paste back into once the user picks a result.
+#[cfg(target_os = "windows")]
+static PREVIOUS_WINDOW: Mutex<Option<isize>> = Mutex::new(None);
+
+pub fn capture_foreground_window() {
+    #[cfg(target_os = "windows")]
+    {
+        let hwnd = unsafe { GetForegroundWindow() };
+        *PREVIOUS_WINDOW.lock().unwrap() = Some(hwnd.0 as isize);
+    }
+}
+
+fn restore_previous_window() -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(handle) = *PREVIOUS_WINDOW.lock().unwrap() {
+            unsafe { SetForegroundWindow(HWND(handle as _)) };
+            return Ok(());
+        }
+    }
+
+    // No reliable handle on macOS/Linux yet, so fall back to cycling back
+    // to whatever was focused before the overlay grabbed focus.
+    #[cfg(target_os = "macos")]
+    let app_switch_modifier = Key::Meta;
+    #[cfg(not(target_os = "macos"))]
+    let app_switch_modifier = Key::Alt;
+
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+    enigo
+        .key(app_switch_modifier, Direction::Press)
+        .map_err(|e| e.to_string())?;
+    enigo
+        .key(Key::Tab, Direction::Click)
+        .map_err(|e| e.to_string())?;
+    enigo
+        .key(app_switch_modifier, Direction::Release)
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn type_str(input: String) -> Result<(), String> {
+    let mut clipboard = Clipboard::new().map_err(|e| e.to_string())?;
+    clipboard.set_text(input).map_err(|e| e.to_string())?;
+
+    restore_previous_window()?;
+    thread::sleep(Duration::from_millis(200));
+
+    let mut enigo = Enigo::new(&Settings::default()).map_err(|e| e.to_string())?;
+
+    #[cfg(target_os = "macos")]
+    let paste_modifier = Key::Meta;
+    #[cfg(not(target_os = "macos"))]
+    let paste_modifier = Key::Control;
+
+    enigo
+        .key(paste_modifier, Direction::Press)
+        .map_err(|e| e.to_string())?;
+    enigo
+        .key(Key::Unicode('v'), Direction::Click)
+        .map_err(|e| e.to_string())?;
+    enigo
+        .key(paste_modifier, Direction::Release)
+        .map_err(|e| e.to_string())?;
+
+    thread::sleep(Duration::from_millis(20));
+
+    Ok(())
+}
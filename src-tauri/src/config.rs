@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::window;
+
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Config {
+    pub shortcut: String,
+    pub launch_at_login: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            shortcut: "CmdOrCtrl+Space".to_string(),
+            launch_at_login: false,
+        }
+    }
+}
+
+fn config_path() -> PathBuf {
+    let dirs = ProjectDirs::from("dev", "zeno", "zeno").expect("no valid home directory found");
+    let dir = dirs.config_dir();
+    fs::create_dir_all(dir).unwrap();
+    dir.join(CONFIG_FILE_NAME)
+}
+
+impl Config {
+    /// Reads the config file, creating it with defaults if it doesn't exist yet.
+    pub fn load() -> Self {
+        Self::load_from(&config_path())
+    }
+
+    pub fn save(&self) {
+        self.save_to(&config_path());
+    }
+
+    fn load_from(path: &Path) -> Self {
+        if !path.exists() {
+            let config = Config::default();
+            config.save_to(path);
+            return config;
+        }
+
+        let contents = fs::read_to_string(path).unwrap();
+        toml::from_str(&contents).unwrap_or_default()
+    }
+
+    fn save_to(&self, path: &Path) {
+        let contents = toml::to_string_pretty(self).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
+
+pub struct ConfigState(pub Mutex<Config>);
+
+/// Shared handler for every registered shortcut: the overlay only ever has
+/// one accelerator bound at a time, so there's no need to dispatch on which
+/// shortcut fired.
+pub fn on_shortcut(app: &AppHandle, _shortcut: &Shortcut, event: tauri_plugin_global_shortcut::ShortcutEvent) {
+    if event.state() == ShortcutState::Pressed {
+        window::toggle(app);
+    }
+}
+
+/// Registers `accelerator` as the global shortcut, falling back to the
+/// built-in default if it's invalid or already bound to something else --
+/// a bad stored shortcut (e.g. from a hand-edited config.toml) must never
+/// keep the app from starting.
+pub fn register_shortcut(app_handle: &AppHandle, accelerator: &str) {
+    if let Err(e) = try_register_shortcut(app_handle, accelerator) {
+        eprintln!("failed to register shortcut '{accelerator}': {e}, falling back to the default");
+
+        let default = Config::default().shortcut;
+        if let Err(e) = try_register_shortcut(app_handle, &default) {
+            eprintln!("failed to register the default shortcut '{default}': {e}");
+        }
+    }
+}
+
+fn try_register_shortcut(app_handle: &AppHandle, accelerator: &str) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|_| format!("'{accelerator}' is not a valid accelerator"))?;
+    app_handle
+        .global_shortcut()
+        .register(shortcut)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_shortcut(
+    app_handle: AppHandle,
+    state: State<ConfigState>,
+    accelerator: String,
+) -> Result<(), String> {
+    let shortcut: Shortcut = accelerator
+        .parse()
+        .map_err(|_| format!("'{accelerator}' is not a valid accelerator"))?;
+
+    let mut config = state.0.lock().unwrap();
+    if accelerator == config.shortcut {
+        return Ok(());
+    }
+    let old_shortcut: Shortcut = config.shortcut.parse().unwrap();
+
+    // Register the new shortcut before giving up the old one: if the OS
+    // refuses it (e.g. already bound by another app), the user keeps a
+    // working hotkey instead of being left with none.
+    app_handle
+        .global_shortcut()
+        .register(shortcut)
+        .map_err(|e| e.to_string())?;
+
+    // The new shortcut is live at this point, which is what matters most;
+    // failing to unregister the now-unused old one isn't worth bailing out
+    // and leaving the config out of sync with it.
+    let _ = app_handle.global_shortcut().unregister(old_shortcut);
+
+    config.shortcut = accelerator;
+    config.save();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("zeno-test-{name}-{:?}.toml", std::thread::current().id()))
+    }
+
+    #[test]
+    fn load_creates_default_when_file_absent() {
+        let path = temp_path("default");
+        let _ = fs::remove_file(&path);
+
+        let config = Config::load_from(&path);
+
+        assert_eq!(config.shortcut, Config::default().shortcut);
+        assert_eq!(config.launch_at_login, Config::default().launch_at_login);
+        assert!(path.exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let path = temp_path("round-trip");
+        let saved = Config {
+            shortcut: "CmdOrCtrl+Shift+Space".to_string(),
+            launch_at_login: true,
+        };
+        saved.save_to(&path);
+
+        let loaded = Config::load_from(&path);
+
+        assert_eq!(loaded.shortcut, saved.shortcut);
+        assert_eq!(loaded.launch_at_login, saved.launch_at_login);
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn load_falls_back_to_default_on_corrupt_toml() {
+        let path = temp_path("corrupt");
+        fs::write(&path, "not valid toml {{{").unwrap();
+
+        let config = Config::load_from(&path);
+
+        assert_eq!(config.shortcut, Config::default().shortcut);
+        assert_eq!(config.launch_at_login, Config::default().launch_at_login);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+mod commands;
+mod config;
+mod tray;
+mod window;
+
+use std::sync::Mutex;
+
+use config::{Config, ConfigState};
+use tauri::Manager;
+use tauri_plugin_autostart::ManagerExt;
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    tauri::Builder::default()
+        .plugin(tauri_plugin_autostart::init(
+            tauri_plugin_autostart::MacosLauncher::LaunchAgent,
+            None,
+        ))
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(config::on_shortcut)
+                .build(),
+        )
+        .invoke_handler(tauri::generate_handler![
+            commands::type_str,
+            window::show_overlay,
+            window::hide_overlay,
+            config::set_shortcut,
+        ])
+        .setup(|app| {
+            let overlay = app
+                .get_webview_window(window::OVERLAY_LABEL)
+                .expect("overlay window is not declared in tauri.conf.json");
+            window::apply_overlay_effects(&overlay);
+            window::watch_focus_loss(&overlay);
+
+            let app_handle = app.handle();
+            let cfg = Config::load();
+
+            tray::build(app_handle, &cfg)?;
+            config::register_shortcut(app_handle, &cfg.shortcut);
+
+            if cfg.launch_at_login {
+                app_handle.autolaunch().enable().unwrap();
+            }
+
+            app.manage(ConfigState(Mutex::new(cfg)));
+
+            Ok(())
+        })
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
@@ -0,0 +1,87 @@
+use tauri::{
+    menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem},
+    tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
+    AppHandle, Manager,
+};
+use tauri_plugin_autostart::ManagerExt;
+
+use crate::config::Config;
+
+const SHOW_ID: &str = "show";
+const LAUNCH_AT_LOGIN_ID: &str = "launch_at_login";
+const QUIT_ID: &str = "quit";
+
+/// The tray's "Launch at login" checkbox, kept around so its check mark can
+/// be updated in place whenever the setting is toggled.
+struct LaunchAtLoginMenuItem(CheckMenuItem<tauri::Wry>);
+
+pub fn build(app: &AppHandle, cfg: &Config) -> tauri::Result<()> {
+    let show = MenuItem::with_id(app, SHOW_ID, "Show", true, None::<&str>)?;
+    let launch_at_login = CheckMenuItem::with_id(
+        app,
+        LAUNCH_AT_LOGIN_ID,
+        "Launch at login",
+        true,
+        cfg.launch_at_login,
+        None::<&str>,
+    )?;
+    let quit = MenuItem::with_id(app, QUIT_ID, "Quit", true, None::<&str>)?;
+
+    let menu = Menu::with_items(
+        app,
+        &[
+            &show,
+            &PredefinedMenuItem::separator(app)?,
+            &launch_at_login,
+            &PredefinedMenuItem::separator(app)?,
+            &quit,
+        ],
+    )?;
+
+    TrayIconBuilder::new()
+        .icon(app.default_window_icon().unwrap().clone())
+        .menu(&menu)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()))
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                crate::window::toggle(tray.app_handle());
+            }
+        })
+        .build(app)?;
+
+    app.manage(LaunchAtLoginMenuItem(launch_at_login));
+
+    Ok(())
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        SHOW_ID => crate::window::toggle(app),
+        LAUNCH_AT_LOGIN_ID => toggle_launch_at_login(app),
+        QUIT_ID => app.exit(0),
+        _ => {}
+    }
+}
+
+fn toggle_launch_at_login(app: &AppHandle) {
+    let state = app.state::<crate::config::ConfigState>();
+    let mut config = state.0.lock().unwrap();
+    config.launch_at_login = !config.launch_at_login;
+
+    let autostart = app.autolaunch();
+    if config.launch_at_login {
+        autostart.enable().unwrap();
+    } else {
+        autostart.disable().unwrap();
+    }
+
+    let menu_item = app.state::<LaunchAtLoginMenuItem>();
+    menu_item.0.set_checked(config.launch_at_login).unwrap();
+
+    config.save();
+}
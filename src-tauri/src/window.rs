@@ -0,0 +1,72 @@
+use tauri::{AppHandle, Manager, WebviewWindow, WindowEvent};
+
+#[cfg(target_os = "macos")]
+use window_vibrancy::{apply_vibrancy, NSVisualEffectMaterial};
+#[cfg(target_os = "windows")]
+use window_vibrancy::apply_acrylic;
+
+use crate::commands;
+
+pub const OVERLAY_LABEL: &str = "overlay";
+
+fn overlay(app: &AppHandle) -> WebviewWindow {
+    app.get_webview_window(OVERLAY_LABEL)
+        .expect("overlay window is not declared in tauri.conf.json")
+}
+
+/// Shows the overlay, capturing the currently-focused app first so
+/// `type_str` knows where to paste back into -- but only if the overlay
+/// isn't already open, since by then it has focus itself.
+pub fn show(window: &WebviewWindow) {
+    if !window.is_visible().unwrap() {
+        commands::capture_foreground_window();
+    }
+
+    window.center().unwrap();
+    window.show().unwrap();
+    window.set_focus().unwrap();
+}
+
+pub fn hide(window: &WebviewWindow) {
+    window.hide().unwrap();
+}
+
+pub fn toggle(app: &AppHandle) {
+    let window = overlay(app);
+    if window.is_visible().unwrap() {
+        hide(&window);
+    } else {
+        show(&window);
+    }
+}
+
+/// Applies the spotlight-style translucent blur behind the overlay.
+pub fn apply_overlay_effects(window: &WebviewWindow) {
+    #[cfg(target_os = "macos")]
+    apply_vibrancy(window, NSVisualEffectMaterial::HudWindow, None, None)
+        .expect("failed to apply vibrancy");
+
+    #[cfg(target_os = "windows")]
+    apply_acrylic(window, Some((18, 18, 18, 125))).expect("failed to apply acrylic");
+}
+
+/// Hides the overlay as soon as it loses focus, so it behaves like a
+/// spotlight popup instead of a regular window.
+pub fn watch_focus_loss(window: &WebviewWindow) {
+    let window = window.clone();
+    window.clone().on_window_event(move |event| {
+        if let WindowEvent::Focused(false) = event {
+            hide(&window);
+        }
+    });
+}
+
+#[tauri::command]
+pub fn show_overlay(app: AppHandle) {
+    show(&overlay(&app));
+}
+
+#[tauri::command]
+pub fn hide_overlay(app: AppHandle) {
+    hide(&overlay(&app));
+}